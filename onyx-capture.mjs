@@ -0,0 +1,63 @@
+#!/usr/bin/env node
+// Append text to the Onyx inbox from the command line:
+//
+//   echo "idea" | node onyx-capture.mjs
+//   node onyx-capture.mjs --note "Projects/Ideas" "text to append"
+//
+// Needs "capture": { "enabled": true } in ~/.config/onyx/settings.json.
+import net from 'node:net';
+import os from 'node:os';
+import path from 'node:path';
+import { readFileSync } from 'node:fs';
+
+const configDir = path.join(os.homedir(), '.config', 'onyx');
+const address = process.platform === 'win32'
+  ? '\\\\.\\pipe\\onyx-capture'
+  : path.join(configDir, 'capture.sock');
+
+const args = process.argv.slice(2);
+let note;
+const noteFlag = args.indexOf('--note');
+if (noteFlag !== -1) {
+  note = args[noteFlag + 1];
+  args.splice(noteFlag, 2);
+}
+
+const readStdin = async () => {
+  let input = '';
+  for await (const chunk of process.stdin) input += chunk;
+  return input;
+};
+
+const text = args.length > 0 ? args.join(' ') : await readStdin();
+if (!text.trim()) {
+  console.error('Nothing to capture');
+  process.exit(1);
+}
+
+let token;
+try {
+  token = readFileSync(path.join(configDir, 'capture.token'), 'utf8').trim();
+} catch {
+  console.error('Onyx is not running or capture is disabled');
+  process.exit(1);
+}
+
+const socket = net.connect(address, () => {
+  socket.write(JSON.stringify({ token, action: 'append', text, note }) + '\n');
+});
+socket.setEncoding('utf8');
+socket.on('data', (data) => {
+  const reply = JSON.parse(data.split('\n')[0]);
+  if (reply.ok) {
+    console.log(`Captured to ${reply.path}`);
+  } else {
+    console.error(`Capture failed: ${reply.error}`);
+    process.exitCode = 1;
+  }
+  socket.end();
+});
+socket.on('error', (err) => {
+  console.error(`Could not reach Onyx: ${err.message}`);
+  process.exit(1);
+});
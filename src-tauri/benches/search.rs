@@ -0,0 +1,60 @@
+//! Vault search over a synthetic 10k-note vault: the memory-mapped byte scan against
+//! reading each note into a String and lowercasing it, as search used to.
+//!
+//! Run with `cargo bench --bench search`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use onyx_lib::bench_support;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const NOTES: usize = 10_000;
+
+/// Notes of a few KB in 100 folders, with every 50th mentioning the search term
+fn synthetic_vault() -> (tempfile::TempDir, Vec<PathBuf>) {
+    let dir = tempfile::tempdir().unwrap();
+    let paragraph = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor \
+                     incididunt ut labore et dolore magna aliqua; see [[Other note]] and #tag.\n";
+    let mut notes = Vec::with_capacity(NOTES);
+    for i in 0..NOTES {
+        let folder = dir.path().join(format!("folder-{:03}", i % 100));
+        fs::create_dir_all(&folder).unwrap();
+        let mut content = format!("---\ntags: [bench]\n---\n# Note {}\n\n", i);
+        for line in 0..40 {
+            content.push_str(paragraph);
+            if i % 50 == 0 && line == 20 {
+                content.push_str("The Quarterly Roadmap is due on Friday.\n");
+            }
+        }
+        let path = folder.join(format!("note-{}.md", i));
+        fs::write(&path, content).unwrap();
+        notes.push(path);
+    }
+    (dir, notes)
+}
+
+/// The previous per-note scan: the whole file as a String, lowercased
+fn scan_lowercased(path: &Path, query: &str) -> bool {
+    let query = query.to_lowercase();
+    fs::read_to_string(path).is_ok_and(|content| content.lines().any(|line| line.to_lowercase().contains(&query)))
+}
+
+fn search(c: &mut Criterion) {
+    let (vault, notes) = synthetic_vault();
+    let mut group = c.benchmark_group("search");
+    group.sample_size(10);
+
+    group.bench_function("search_files", |b| {
+        b.iter(|| assert_eq!(bench_support::search(vault.path(), black_box("quarterly roadmap")), NOTES / 50))
+    });
+    group.bench_function("scan/mapped", |b| {
+        b.iter(|| notes.iter().filter(|note| bench_support::scan(note, black_box("quarterly roadmap"))).count())
+    });
+    group.bench_function("scan/read_lowercased", |b| {
+        b.iter(|| notes.iter().filter(|note| scan_lowercased(note, black_box("quarterly roadmap"))).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, search);
+criterion_main!(benches);
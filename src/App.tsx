@@ -28,11 +28,13 @@ import { onOpenUrl, getCurrent as getDeepLinkCurrent } from '@tauri-apps/plugin-
 import { readText } from '@tauri-apps/plugin-clipboard-manager';
 import { getSyncEngine, getCurrentLogin } from './lib/nostr';
 import { getSignerFromStoredLogin } from './lib/nostr/signer';
-import { buildNoteIndex, resolveWikilink, NoteIndex, FileEntry, NoteGraph, buildNoteGraph } from './lib/editor/note-index';
+import { buildNoteIndex, resolveWikilink, NoteIndex, FileEntry, NoteGraph, buildNoteGraph, CanvasLink, isCanvasPath } from './lib/editor/note-index';
 import { openDailyNote, loadDailyNotesConfig } from './lib/daily-notes';
+import { loadVaultConfig, mergeDailyNotesConfig, mergeTemplatesConfig } from './lib/vault-config';
 import { listTemplates, getTemplateContent, createNoteFromTemplate, loadTemplatesConfig, type TemplateInfo } from './lib/templates';
 import { HeadingInfo } from './lib/editor/heading-plugin';
-import { AssetIndex, AssetEntry, buildAssetIndex } from './lib/editor/asset-index';
+import { AssetIndex, AssetEntry, buildAssetIndex, syncSupportedExtensions } from './lib/editor/asset-index';
+import { journalChange, offerJournalRecovery } from './lib/journal';
 import type { SharedDocument, SentShare, Vault } from './lib/nostr/types';
 
 interface Tab {
@@ -45,6 +47,9 @@ interface Tab {
 interface AppSettings {
   vault_path: string | null;
   show_terminal: boolean;
+  archive_folder_pattern?: string | null;
+  stamp_created_date?: boolean;
+  capture?: { enabled: boolean; inbox?: string };
 }
 
 type SidebarView = 'files' | 'search' | 'bookmarks';
@@ -78,6 +83,8 @@ const App: Component = () => {
   const [showCommandPalette, setShowCommandPalette] = createSignal(false);
   const [showSearch, setShowSearch] = createSignal(false);
   const [showTerminal, setShowTerminal] = createSignal(false);
+  // Last loaded settings, so saving keeps fields the UI doesn't edit
+  let storedSettings: Partial<AppSettings> = {};
   // Editor view mode: 'live' = rendered markdown, 'source' = raw markdown
   const [editorViewMode, setEditorViewMode] = createSignal<'live' | 'source'>(
     (localStorage.getItem('editor_view_mode') as 'live' | 'source') || 'live'
@@ -131,6 +138,7 @@ const App: Component = () => {
   // Note graph and file contents for backlinks
   const [noteGraph, setNoteGraph] = createSignal<NoteGraph | null>(null);
   const [fileContents, setFileContents] = createSignal<Map<string, string>>(new Map());
+  const [canvasLinks, setCanvasLinks] = createSignal<CanvasLink[]>([]);
 
   // Outline panel state
   const [showOutline, setShowOutline] = createSignal(
@@ -335,6 +343,7 @@ const App: Component = () => {
 
     // Load settings asynchronously
     invoke<AppSettings>('load_settings').then(async (settings) => {
+      storedSettings = settings;
       console.log('[App] Settings loaded:', settings);
       let vaultToOpen = settings.vault_path;
       
@@ -357,23 +366,35 @@ const App: Component = () => {
         }
       }
       
-      if (vaultToOpen) {
-        console.log('[App] Setting vault path to:', vaultToOpen);
-        setVaultPath(vaultToOpen);
-        // Build note index for wikilink resolution
-        try {
-          const files = await invoke<FileEntry[]>('list_files', { path: vaultToOpen });
-          setNoteIndex(buildNoteIndex(files, vaultToOpen));
-        } catch (err) {
-          console.error('Failed to build initial note index:', err);
-        }
-        // Build asset index for embed resolution
+      // A stale vault path (e.g. an unmounted drive) would otherwise open as an empty vault
+      if (vaultToOpen && !isMobileApp()) {
         try {
-          const assets = await invoke<AssetEntry[]>('list_assets', { path: vaultToOpen });
-          setAssetIndex(buildAssetIndex(assets, vaultToOpen));
+          const access = await invoke<{ exists: boolean; is_dir: boolean; readable: boolean }>(
+            'check_vault_accessible', { vaultPath: vaultToOpen }
+          );
+          if (!access.readable) {
+            const { ask } = await import('@tauri-apps/plugin-dialog');
+            const relocate = await ask(
+              `The vault at ${vaultToOpen} is ${access.exists ? 'not readable' : 'missing'}. Choose its new location?`,
+              { title: 'Vault unavailable', kind: 'warning' }
+            );
+            if (relocate) {
+              vaultToOpen = (await openVault()) ?? vaultToOpen;
+            }
+          }
         } catch (err) {
-          console.error('Failed to build initial asset index:', err);
+          console.error('Failed to check vault access:', err);
         }
+      }
+
+      if (vaultToOpen) {
+        console.log('[App] Setting vault path to:', vaultToOpen);
+        // Supported extensions must be known before the asset index streams in
+        await syncSupportedExtensions();
+        // Note and asset indexes arrive as vault-open-progress events
+        setVaultPath(vaultToOpen);
+        // Offer to restore edits lost in a crash before anything is opened
+        await offerJournalRecovery(vaultToOpen);
         // Process any deep links that arrived before vault was ready
         processPendingDeepLinks();
       }
@@ -440,7 +461,10 @@ const App: Component = () => {
             // If the content is different from what we have, reload it
             // This handles both dirty and non-dirty cases - external changes take precedence
             // (e.g., OpenCode editing a file should always be reflected in the editor)
-            if (newContent !== tab.content) {
+            // Saves may rewrite line endings or add a final newline per the vault
+            // settings; that alone is not an external change
+            const normalize = (text: string) => text.replace(/\r\n/g, '\n').replace(/\n$/, '');
+            if (normalize(newContent) !== normalize(tab.content)) {
               // Cancel any pending autosave to prevent overwriting external changes
               if (autoSaveTimeout) {
                 clearTimeout(autoSaveTimeout);
@@ -461,10 +485,85 @@ const App: Component = () => {
       unlistenFileModified = unlisten;
     });
 
+    // Saves from a note opened with open_in_editor, reported as soon as they land
+    let unlistenExternalEdit: (() => void) | null = null;
+    listen<string>('file-modified-external', async (event) => {
+      const tab = tabs().find(t => t.path === event.payload);
+      // Unsaved in-app edits are left to the regular file-modified handling
+      if (!tab || tab.isDirty) return;
+      try {
+        const content = await invoke<string>('read_file', { path: event.payload });
+        if (content !== tab.content) {
+          setTabs(prevTabs => prevTabs.map(t => (t.path === event.payload ? { ...t, content } : t)));
+        }
+      } catch (err) {
+        console.error('Failed to reload externally edited file:', err);
+      }
+    }).then(unlisten => {
+      unlistenExternalEdit = unlisten;
+    });
+
+    // Background parts of open_vault finishing
+    let unlistenVaultOpen: (() => void) | null = null;
+    listen<{ vault: string; subsystem: string; ok: boolean; data?: unknown; error?: string }>(
+      'vault-open-progress',
+      (event) => {
+        const { vault, subsystem, ok, data, error } = event.payload;
+        if (vault !== vaultPath()) return;
+        if (!ok) {
+          console.error(`[App] Opening vault: ${subsystem} failed:`, error);
+        } else if (subsystem === 'tree' && data) {
+          setNoteIndex(buildNoteIndex(data as FileEntry[], vault));
+        } else if (subsystem === 'assets' && data) {
+          setAssetIndex(buildAssetIndex(data as AssetEntry[], vault));
+        }
+      }
+    ).then(unlisten => {
+      unlistenVaultOpen = unlisten;
+    });
+
+    // Text appended by external tools through the capture socket
+    let unlistenCapture: (() => void) | null = null;
+    listen<{ path: string; text: string }>('capture-received', async (event) => {
+      refreshSidebar?.();
+      const tab = tabs().find(t => t.path === event.payload.path);
+      if (!tab || tab.isDirty) return;
+      try {
+        const content = await invoke<string>('read_file', { path: event.payload.path });
+        setTabs(prevTabs => prevTabs.map(t => (t.path === event.payload.path ? { ...t, content } : t)));
+      } catch (err) {
+        console.error('Failed to reload captured note:', err);
+      }
+    }).then(unlisten => {
+      unlistenCapture = unlisten;
+    });
+
+    // note:// links clicked in rendered previews, resolved by the backend
+    let unlistenOpenNote: (() => void) | null = null;
+    listen<{ resolved_path: string; exists: boolean }>('open-note', async (event) => {
+      const { resolved_path, exists } = event.payload;
+      // An [[id:...]] link to an id no note carries has nowhere to create a note
+      if (!resolved_path) return;
+      try {
+        if (!exists) {
+          await invoke('create_file', { path: resolved_path });
+        }
+        await openFile(resolved_path);
+      } catch (err) {
+        console.error('Failed to open linked note:', err);
+      }
+    }).then(unlisten => {
+      unlistenOpenNote = unlisten;
+    });
+
     // Cleanup must be registered synchronously
     onCleanup(() => {
       unlistenFn?.();
       unlistenFileModified?.();
+      unlistenExternalEdit?.();
+      unlistenOpenNote?.();
+      unlistenVaultOpen?.();
+      unlistenCapture?.();
       invoke('stop_watching').catch(() => {});
       if (sharePollingInterval) {
         clearInterval(sharePollingInterval);
@@ -671,6 +770,17 @@ const App: Component = () => {
       return;
     }
     
+    const id = params.get('id');
+    if (id) {
+      const notePath = await invoke<string | null>('get_note_by_id', { vaultPath: vault, id });
+      if (notePath) {
+        await openFile(notePath);
+      } else {
+        console.error('[DeepLink] No note with id', id);
+      }
+      return;
+    }
+
     const path = params.get('path');
     if (!path) {
       console.error('[DeepLink] No path provided');
@@ -700,24 +810,9 @@ const App: Component = () => {
     
     // Set the vault path from onboarding
     if (result.vaultPath) {
+      // Note and asset indexes arrive as vault-open-progress events
       setVaultPath(result.vaultPath);
       localStorage.setItem('vault_path', result.vaultPath);
-      
-      // Build note index for the new vault
-      try {
-        const files = await invoke<FileEntry[]>('list_files', { path: result.vaultPath });
-        setNoteIndex(buildNoteIndex(files, result.vaultPath));
-      } catch (err) {
-        console.error('Failed to build note index after onboarding:', err);
-      }
-      
-      // Build asset index
-      try {
-        const assets = await invoke<AssetEntry[]>('list_assets', { path: result.vaultPath });
-        setAssetIndex(buildAssetIndex(assets, result.vaultPath));
-      } catch (err) {
-        console.error('Failed to build asset index after onboarding:', err);
-      }
     }
     
     // Apply sync settings if enabled during onboarding
@@ -929,8 +1024,11 @@ const App: Component = () => {
   // Start/stop file watcher when vault path changes
   createEffect(() => {
     const path = vaultPath();
+    // The asset protocol only serves files inside the active vault
+    invoke('set_active_vault', { path: path ?? null }).catch(console.error);
     if (path) {
-      invoke('start_watching', { path }).catch(console.error);
+      // Starts the watcher and builds the indexes in the background
+      invoke('open_vault', { path }).catch(console.error);
     } else {
       invoke('stop_watching').catch(() => {});
     }
@@ -952,6 +1050,7 @@ const App: Component = () => {
     console.log('[App] Saving settings - vault_path:', path);
     invoke('save_settings', {
       settings: {
+        ...storedSettings,
         vault_path: path,
         show_terminal: terminal,
       }
@@ -1018,6 +1117,7 @@ const App: Component = () => {
     if (!index || !vault) {
       setNoteGraph(null);
       setFileContents(new Map());
+      setCanvasLinks([]);
       return;
     }
 
@@ -1031,7 +1131,12 @@ const App: Component = () => {
         return content;
       };
 
-      const graph = await buildNoteGraph(vault, index, readFile);
+      const cards = await invoke<CanvasLink[]>('list_canvas_links', { vaultPath: vault }).catch((err) => {
+        console.warn('Failed to list canvas links:', err);
+        return [];
+      });
+      const graph = await buildNoteGraph(vault, index, readFile, cards);
+      setCanvasLinks(cards);
       setNoteGraph(graph);
       setFileContents(contents);
     } catch (err) {
@@ -1066,6 +1171,12 @@ const App: Component = () => {
   const [syncStatus, setSyncStatus] = createSignal<'off' | 'idle' | 'syncing' | 'error'>('off');
 
   const openFile = async (path: string, line?: number) => {
+    // The editor only handles markdown; canvases open in the app that made them
+    if (isCanvasPath(path)) {
+      invoke('open_in_default_app', { path }).catch(err => console.error('Failed to open canvas:', err));
+      return;
+    }
+
     // Check if already open
     const existingIndex = tabs().findIndex(t => t.path === path);
     if (existingIndex >= 0) {
@@ -1161,6 +1272,8 @@ const App: Component = () => {
     const idx = activeTabIndex();
     if (idx < 0) return;
 
+    const tab = tabs()[idx];
+    journalChange(tab.path, tab.content, content);
     setTabs(tabs().map((t, i) => i === idx ? { ...t, content, isDirty: true } : t));
 
     // Auto-save after 2 seconds of no typing
@@ -1552,13 +1665,15 @@ const App: Component = () => {
       return;
     }
 
-    // Resolve the wikilink to a file path
-    const resolved = resolveWikilink(
-      target,
-      currentTab()?.path || '',
-      index,
-      vault
-    );
+    // Resolve the wikilink to a file path; [[id:...]] links go by frontmatter id
+    const resolved = target.startsWith('id:')
+      ? await invoke<{ resolved_path: string; exists: boolean }>('resolve_note_link', {
+          vaultPath: vault,
+          target,
+        })
+          .then((r) => ({ path: r.resolved_path, exists: r.exists }))
+          .catch(() => ({ path: '', exists: false }))
+      : resolveWikilink(target, currentTab()?.path || '', index, vault);
 
     if (resolved.exists && resolved.path) {
       // Open existing note
@@ -1798,7 +1913,7 @@ const App: Component = () => {
     const vault = vaultPath();
     if (!vault) return;
     
-    const config = loadDailyNotesConfig();
+    const config = mergeDailyNotesConfig(loadDailyNotesConfig(), await loadVaultConfig(vault));
     if (!config.enabled) {
       console.log('[DailyNotes] Daily notes are disabled');
       return;
@@ -1837,7 +1952,7 @@ const App: Component = () => {
     
     setTemplatesLoading(true);
     try {
-      const config = loadTemplatesConfig();
+      const config = mergeTemplatesConfig(loadTemplatesConfig(), await loadVaultConfig(vault));
       const templates = await listTemplates(vault, config);
       setAvailableTemplates(templates);
       setShowTemplatesModal(true);
@@ -2441,7 +2556,7 @@ const App: Component = () => {
                     if (index && vault) {
                       const graph = await buildNoteGraph(vault, index, async (path: string) => {
                         return newContents.get(path) || await invoke<string>('read_file', { path });
-                      });
+                      }, canvasLinks());
                       setNoteGraph(graph);
                     }
                   } catch (err) {
@@ -2464,6 +2579,8 @@ const App: Component = () => {
                 onUpdateContent={(newContent) => {
                   const idx = activeTabIndex();
                   if (idx >= 0) {
+                    const tab = tabs()[idx];
+                    journalChange(tab.path, tab.content, newContent);
                     setTabs(tabs().map((t, i) => i === idx ? { ...t, content: newContent, isDirty: true } : t));
                     // Trigger auto-save
                     if (autoSaveTimeout) clearTimeout(autoSaveTimeout);
@@ -2503,7 +2620,7 @@ const App: Component = () => {
             <div style={{ width: `${terminalWidth()}px` }}>
               <OpenCodePanel
                 vaultPath={vaultPath()}
-                currentFile={currentTab() ? { path: currentTab()!.path, content: currentTab()!.content } : null}
+                currentFile={currentTab() ? { path: currentTab()!.path, content: currentTab()!.content, isDirty: currentTab()!.isDirty } : null}
                 vaultFiles={noteIndex() ? Array.from(noteIndex()!.allPaths).map(path => ({
                   path,
                   name: path.replace(/\\/g, '/').split('/').pop()?.replace(/\.md$/i, '') || path
@@ -30,6 +30,7 @@ import { highlightPlugin } from '../lib/editor/highlight-plugin';
 import { commentPlugin } from '../lib/editor/comment-plugin';
 import { calloutPlugin } from '../lib/editor/callout-plugin';
 import { AssetIndex } from '../lib/editor/asset-index';
+import { attachmentFolder } from '../lib/vault-config';
 import {
   vaultUploadPlugin,
   setUploadVaultPath,
@@ -261,10 +262,11 @@ const MilkdownEditor: Component<EditorProps> = (props) => {
 
             // Extract and sanitize filename
             const rawName = sourcePath.replace(/\\/g, '/').split('/').pop() || `file.${ext}`;
-            const fileName = await getUniqueFileNameInVault(props.vaultPath, 'attachments', sanitizeFileName(rawName));
+            const folder = await attachmentFolder(props.vaultPath);
+            const fileName = await getUniqueFileNameInVault(props.vaultPath, folder, sanitizeFileName(rawName));
 
             // Ensure attachments directory exists
-            const attachmentsDir = joinPath(props.vaultPath, 'attachments');
+            const attachmentsDir = joinPath(props.vaultPath, folder);
             if (!(await exists(attachmentsDir))) {
               console.log('[DragDrop] Creating attachments directory:', attachmentsDir);
               await mkdir(attachmentsDir, { recursive: true });
@@ -283,7 +285,7 @@ const MilkdownEditor: Component<EditorProps> = (props) => {
               // Insert embed at cursor position
               const embedType = view.state.schema.nodes.embed;
               if (embedType) {
-                const relativePath = `attachments/${fileName}`;
+                const relativePath = `${folder}/${fileName}`;
                 const node = embedType.create({
                   target: relativePath,
                   anchor: null,
@@ -27,6 +27,7 @@ import {
 } from '../lib/opencode/client';
 import { getCurrentLogin, getSavedProfile, type UserProfile } from '../lib/nostr/login';
 import { sanitizeImageUrl, sanitizeUrl } from '../lib/security';
+import { errorCode, errorMessage } from '../lib/errors';
 
 // Install progress payload from Rust backend
 interface InstallProgress {
@@ -103,7 +104,7 @@ interface VaultFile {
 
 interface OpenCodeChatProps {
   vaultPath: string | null;
-  currentFile?: { path: string; content: string } | null;
+  currentFile?: { path: string; content: string; isDirty?: boolean } | null;
   vaultFiles?: VaultFile[];
 }
 
@@ -289,12 +290,11 @@ const OpenCodeChat: Component<OpenCodeChatProps> = (props) => {
       }
     } catch (err) {
       console.error('Failed to start OpenCode server:', err);
-      const errorStr = String(err).toLowerCase();
-      if (errorStr.includes('not found') || errorStr.includes('no such file')) {
+      if (errorCode(err) === 'NOT_FOUND') {
         setServerStatus('not-installed');
       } else {
         setServerStatus('error');
-        setError(String(err));
+        setError(errorMessage(err));
       }
     }
   };
@@ -823,7 +823,24 @@ const OpenCodeChat: Component<OpenCodeChatProps> = (props) => {
           }
           
           const truncateNote = truncated ? `\n\n[Note: File truncated - showing first ${MAX_CONTEXT_LINES} lines or ${Math.round(MAX_CONTEXT_CHARS/1000)}k chars]` : '';
-          prompt = `[Context: Working on file "${filePath}"]${truncateNote}\n\n${content}\n\n---\n\n${text}`;
+          let context = `${truncateNote}\n\n${content}`;
+
+          // Prefer the backend's bundle: the note plus linked notes and backlinks, within budget
+          if (props.vaultPath && !file.isDirty) {
+            try {
+              const bundle = await invoke<{ text: string }>('build_ai_context', {
+                vaultPath: props.vaultPath,
+                focusPath: filePath,
+                budgetChars: MAX_CONTEXT_CHARS,
+                options: { selection: window.getSelection()?.toString() || null },
+              });
+              context = `\n\n${bundle.text}`;
+            } catch (err) {
+              console.warn('Falling back to plain note context:', err);
+            }
+          }
+
+          prompt = `[Context: Working on file "${filePath}"]${context}\n\n---\n\n${text}`;
           setContextSentForFile(fileKey);
         }
       }
@@ -1041,7 +1058,7 @@ const OpenCodeChat: Component<OpenCodeChatProps> = (props) => {
     } catch (err) {
       console.error('Failed to install OpenCode:', err);
       unlisten();
-      setInstallError(err instanceof Error ? err.message : String(err));
+      setInstallError(errorMessage(err));
       setIsInstalling(false);
     }
   };
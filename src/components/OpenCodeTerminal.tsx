@@ -5,6 +5,7 @@ import { CanvasAddon } from '@xterm/addon-canvas';
 import { invoke } from '@tauri-apps/api/core';
 import { listen, UnlistenFn } from '@tauri-apps/api/event';
 import { open } from '@tauri-apps/plugin-shell';
+import { errorCode, errorMessage } from '../lib/errors';
 
 interface OpenCodeTerminalProps {
   vaultPath: string | null;
@@ -133,12 +134,10 @@ const OpenCodeTerminal: Component<OpenCodeTerminalProps> = (props) => {
       terminal.focus();
     } catch (err) {
       console.error('Failed to start opencode:', err);
-      const errorStr = String(err).toLowerCase();
-      // Check if error indicates opencode is not found
-      if (errorStr.includes('not found') || errorStr.includes('no such file') || errorStr.includes('command not found')) {
+      if (errorCode(err) === 'NOT_FOUND') {
         setNotInstalled(true);
       } else if (terminal) {
-        terminal.write(`\x1b[31mFailed to start opencode: ${err}\x1b[0m\r\n`);
+        terminal.write(`\x1b[31mFailed to start opencode: ${errorMessage(err)}\x1b[0m\r\n`);
         terminal.write('\x1b[33mMake sure opencode is installed and in your PATH.\x1b[0m\r\n');
       }
     }
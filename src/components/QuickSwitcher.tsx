@@ -5,9 +5,23 @@ interface FileEntry {
   name: string;
   path: string;
   isDirectory: boolean;
+  kind?: 'folder' | 'note' | 'canvas';
   children?: FileEntry[];
 }
 
+interface SwitcherItem {
+  name: string;
+  path: string;
+  // Frontmatter alias the query matched, for notes found by alias only
+  alias?: string;
+}
+
+interface LinkCandidate {
+  path: string;
+  relative: string;
+  matched_alias: string | null;
+}
+
 interface QuickSwitcherProps {
   vaultPath: string | null;
   onSelect: (path: string) => void;
@@ -17,16 +31,17 @@ interface QuickSwitcherProps {
 const QuickSwitcher: Component<QuickSwitcherProps> = (props) => {
   const [query, setQuery] = createSignal('');
   const [files, setFiles] = createSignal<{ name: string; path: string }[]>([]);
-  const [filtered, setFiltered] = createSignal<{ name: string; path: string }[]>([]);
+  const [filtered, setFiltered] = createSignal<SwitcherItem[]>([]);
+  const [aliasMatches, setAliasMatches] = createSignal<SwitcherItem[]>([]);
   const [selectedIndex, setSelectedIndex] = createSignal(0);
   let inputRef: HTMLInputElement | undefined;
 
-  // Flatten file tree to get all files
+  // Flatten file tree to get all files. Canvases are left out, since the editor can't open them.
   const flattenFiles = (entries: FileEntry[], result: { name: string; path: string }[] = []) => {
     for (const entry of entries) {
       if (entry.isDirectory && entry.children) {
         flattenFiles(entry.children, result);
-      } else if (!entry.isDirectory) {
+      } else if (!entry.isDirectory && entry.kind !== 'canvas') {
         result.push({ name: entry.name, path: entry.path });
       }
     }
@@ -46,25 +61,48 @@ const QuickSwitcher: Component<QuickSwitcherProps> = (props) => {
     inputRef?.focus();
   });
 
+  // Notes matched through a frontmatter alias rather than their name
+  createEffect(() => {
+    const q = query();
+    if (!q || !props.vaultPath) {
+      setAliasMatches([]);
+      return;
+    }
+    invoke<LinkCandidate[]>('get_link_candidates', { vaultPath: props.vaultPath, query: q, limit: 20 })
+      .then(candidates => {
+        if (query() !== q) return;
+        setAliasMatches(candidates
+          .filter(c => c.matched_alias)
+          .map(c => ({
+            name: c.relative.split('/').pop() || c.relative,
+            path: c.path,
+            alias: c.matched_alias!,
+          })));
+      })
+      .catch(err => console.error('Failed to match aliases:', err));
+  });
+
   // Filter files based on query
   createEffect(() => {
     const q = query().toLowerCase();
     if (!q) {
       setFiltered(files());
     } else {
-      setFiltered(
-        files().filter(f =>
-          f.name.toLowerCase().includes(q) ||
-          f.path.toLowerCase().includes(q)
-        ).sort((a, b) => {
-          // Prioritize matches at start of name
-          const aStartsWith = a.name.toLowerCase().startsWith(q);
-          const bStartsWith = b.name.toLowerCase().startsWith(q);
-          if (aStartsWith && !bStartsWith) return -1;
-          if (!aStartsWith && bStartsWith) return 1;
-          return a.name.localeCompare(b.name);
-        })
-      );
+      const byName = files().filter(f =>
+        f.name.toLowerCase().includes(q) ||
+        f.path.toLowerCase().includes(q)
+      ).sort((a, b) => {
+        // Prioritize matches at start of name
+        const aStartsWith = a.name.toLowerCase().startsWith(q);
+        const bStartsWith = b.name.toLowerCase().startsWith(q);
+        if (aStartsWith && !bStartsWith) return -1;
+        if (!aStartsWith && bStartsWith) return 1;
+        return a.name.localeCompare(b.name);
+      });
+      // Alias hits rank after names starting with the query, ahead of other name matches
+      const aliases = aliasMatches().filter(a => !byName.some(f => f.name === a.name));
+      const leading = byName.filter(f => f.name.toLowerCase().startsWith(q));
+      setFiltered([...leading, ...aliases, ...byName.slice(leading.length)]);
     }
     setSelectedIndex(0);
   });
@@ -107,7 +145,7 @@ const QuickSwitcher: Component<QuickSwitcherProps> = (props) => {
                 onClick={() => props.onSelect(file.path)}
                 onMouseEnter={() => setSelectedIndex(index())}
               >
-                <span class="file-name">{file.name}</span>
+                <span class="file-name">{file.alias ? `${file.alias} → ${file.name}` : file.name}</span>
                 <span class="file-path">{file.path.replace(props.vaultPath + '/', '')}</span>
               </div>
             )}
@@ -1,5 +1,7 @@
 import { Component, createSignal, For, onMount } from 'solid-js';
 import { invoke } from '@tauri-apps/api/core';
+import { ask } from '@tauri-apps/plugin-dialog';
+import { errorCode } from '../lib/errors';
 
 interface SearchResult {
   path: string;
@@ -53,6 +55,39 @@ const SearchPanel: Component<SearchPanelProps> = (props) => {
     }, 300);
   };
 
+  // Save the results as a note of links. If the note exists, ask before regenerating its results section.
+  const exportResults = async () => {
+    const vault = props.vaultPath;
+    const searchQuery = query().trim();
+    if (!vault || !searchQuery) return;
+    const fileName = `Search - ${searchQuery.replace(/[\\/:*?"<>|#^[\]]/g, ' ').trim()}.md`;
+    const save = (refresh: boolean) =>
+      invoke<{ path: string }>('export_search_results', {
+        vaultPath: vault,
+        destPath: `${vault}/${fileName}`,
+        query: searchQuery,
+        options: { refresh },
+      });
+    try {
+      let exported;
+      try {
+        exported = await save(false);
+      } catch (err) {
+        if (errorCode(err) !== 'ALREADY_EXISTS') throw err;
+        const refresh = await ask(`"${fileName}" already exists. Update the search results in it?`, {
+          title: 'Save search as note',
+          kind: 'info',
+          okLabel: 'Update',
+        });
+        if (!refresh) return;
+        exported = await save(true);
+      }
+      props.onSelect(exported.path);
+    } catch (err) {
+      console.error('Failed to export search results:', err);
+    }
+  };
+
   const handleKeyDown = (e: KeyboardEvent) => {
     if (e.key === 'Escape') {
       props.onClose();
@@ -74,6 +109,11 @@ const SearchPanel: Component<SearchPanelProps> = (props) => {
             onInput={(e) => handleInput(e.currentTarget.value)}
             onKeyDown={handleKeyDown}
           />
+          {results().length > 0 && (
+            <button class="search-export" onClick={exportResults} title="Save results as a note">
+              Save as note
+            </button>
+          )}
           <button class="search-close" onClick={props.onClose}>×</button>
         </div>
         <div class="search-results">
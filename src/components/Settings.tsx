@@ -1,6 +1,7 @@
 import { Component, createSignal, For, Show, onMount, onCleanup, createEffect } from 'solid-js';
 import { invoke } from '@tauri-apps/api/core';
 import { getVersion } from '@tauri-apps/api/app';
+import { errorMessage } from '../lib/errors';
 import { open } from '@tauri-apps/plugin-shell';
 import { open as openDialog } from '@tauri-apps/plugin-dialog';
 import {
@@ -40,6 +41,7 @@ import {
 } from '../lib/opencode/client';
 import {
   fetchSkillsShLeaderboard,
+  prefetchSkillsShFiles,
   searchSkillsSh,
   sortSkillsSh,
   formatInstallCount,
@@ -107,6 +109,8 @@ interface SkillState {
 // Skills manifest URL
 const SKILLS_MANIFEST_URL = 'https://raw.githubusercontent.com/derekross/onyx-skills/main/manifest.json';
 const SKILLS_BASE_URL = 'https://raw.githubusercontent.com/derekross/onyx-skills/main';
+// skills.sh skills whose SKILL.md is fetched as soon as the library loads
+const SKILLS_SH_PREFETCH_COUNT = 24;
 
 const sections: SettingsSectionItem[] = [
   { id: 'general', label: 'General', icon: 'M12 2L2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5' },
@@ -196,6 +200,7 @@ const Settings: Component<SettingsProps> = (props) => {
   // Skills.sh library state
   type SkillsTab = 'recommended' | 'browse' | 'installed';
   const [skillsTab, setSkillsTab] = createSignal<SkillsTab>('recommended');
+  const [skillsDirPath, setSkillsDirPath] = createSignal('~/.config/opencode/skills/');
   const [skillsShList, setSkillsShList] = createSignal<SkillsShSkill[]>([]);
   const [skillsShLoading, setSkillsShLoading] = createSignal(false);
   const [skillsShError, setSkillsShError] = createSignal<string | null>(null);
@@ -371,6 +376,8 @@ const Settings: Component<SettingsProps> = (props) => {
     };
     mediaQuery.addEventListener('change', handleThemeChange);
 
+    invoke<string>('get_skills_dir_path').then(setSkillsDirPath).catch(() => {});
+
     // Load OpenCode path setting
     const savedOpenCodePath = localStorage.getItem('opencode_path');
     if (savedOpenCodePath) {
@@ -546,7 +553,7 @@ const Settings: Component<SettingsProps> = (props) => {
       }
 
       // Get all locally installed skills
-      const installedSkillIds = await invoke<string[]>('skill_list_installed');
+      const installedSkillIds = (await invoke<{ id: string }[]>('skill_list_installed')).map((s) => s.id);
 
       // Find custom skills (installed but not in manifest)
       const customSkills: SkillInfo[] = [];
@@ -593,6 +600,8 @@ const Settings: Component<SettingsProps> = (props) => {
     try {
       const skills = await fetchSkillsShLeaderboard();
       setSkillsShList(skills);
+      // Most-installed skills are the likeliest to be opened; don't wait for them
+      prefetchSkillsShFiles(skills.slice(0, SKILLS_SH_PREFETCH_COUNT));
 
       // Check which skills.sh skills are already installed
       const installed = new Set<string>();
@@ -1840,7 +1849,7 @@ const Settings: Component<SettingsProps> = (props) => {
       setModalConfig({
         type: 'info',
         title: 'Import failed',
-        message: `Failed to import skill: ${err instanceof Error ? err.message : 'Unknown error'}`
+        message: `Failed to import skill: ${errorMessage(err)}`
       });
     }
   };
@@ -1976,7 +1985,7 @@ const Settings: Component<SettingsProps> = (props) => {
                   <div class="setting-info">
                     <div class="setting-name">Daily note template</div>
                     <div class="setting-description">
-                      Content template for new daily notes. Use {'{{date}}'}, {'{{date:FORMAT}}'}, {'{{time}}'}, {'{{title}}'} for variables.
+                      Content template for new daily notes. Use {'{{date}}'}, {'{{date:FORMAT}}'}, {'{{time}}'}, {'{{title}}'} for variables, and {'{{events}}'} for the day's calendar events.
                     </div>
                   </div>
                   <textarea
@@ -2888,7 +2897,9 @@ const Settings: Component<SettingsProps> = (props) => {
                     <line x1="12" y1="16" x2="12" y2="12"></line>
                     <line x1="12" y1="8" x2="12.01" y2="8"></line>
                   </svg>
-                  <p>AI skills enhance OpenCode with specialized capabilities. Skills are stored in <code>~/.config/opencode/skills/</code></p>
+                  <p>AI skills enhance OpenCode with specialized capabilities. Skills are stored in <code>{skillsDirPath()}</code>{' '}
+                    <button class="link-button" onClick={() => invoke('show_in_folder', { path: skillsDirPath() }).catch(console.error)}>Show in folder</button>
+                  </p>
                 </div>
 
                 {/* Skills tabs */}
@@ -6,9 +6,15 @@ interface FileEntry {
   name: string;
   path: string;
   isDirectory: boolean;
+  kind?: 'folder' | 'note' | 'canvas';
   children?: FileEntry[];
+  noteCount?: number | null;
+  latestModified?: number | null;
 }
 
+const isToday = (timestamp?: number | null) =>
+  timestamp != null && new Date(timestamp).toDateString() === new Date().toDateString();
+
 type SidebarView = 'files' | 'search' | 'bookmarks';
 
 interface SidebarProps {
@@ -65,7 +71,7 @@ const Sidebar: Component<SidebarProps> = (props) => {
   const [sortOrder, setSortOrder] = createSignal<'name' | 'modified'>('name');
   const [allCollapsed, setAllCollapsed] = createSignal(false);
   const [showVaultMenu, setShowVaultMenu] = createSignal(false);
-  const [deleteConfirm, setDeleteConfirm] = createSignal<{ path: string; name: string } | null>(null);
+  const [deleteConfirm, setDeleteConfirm] = createSignal<{ path: string; name: string; count?: number } | null>(null);
   const [moveConfirm, setMoveConfirm] = createSignal<{ sourcePath: string; destPath: string; name: string } | null>(null);
 
   const openVault = async () => {
@@ -224,7 +230,7 @@ const Sidebar: Component<SidebarProps> = (props) => {
     closeContextMenu();
   };
 
-  const confirmRename = async (oldPath: string) => {
+  const confirmRename = async (oldPath: string, isDirectory = false) => {
     if (!renameValue()) return;
 
     const parentPath = oldPath.substring(0, oldPath.lastIndexOf('/'));
@@ -238,10 +244,17 @@ const Sidebar: Component<SidebarProps> = (props) => {
     const newPath = `${parentPath}/${newName}`;
 
     try {
-      await invoke('rename_file', { oldPath, newPath });
+      if (isDirectory && props.vaultPath) {
+        // Links into the folder and relative links out of it follow the rename
+        await invoke<number>('rename_folder', { oldPath, newPath, vaultPath: props.vaultPath });
+      } else {
+        await invoke('rename_file', { oldPath, newPath });
+      }
       await refreshFiles();
       if (props.currentFile === oldPath) {
         props.onFileSelect(newPath);
+      } else if (isDirectory && props.currentFile?.startsWith(`${oldPath}/`)) {
+        props.onFileSelect(newPath + props.currentFile.slice(oldPath.length));
       }
     } catch (err) {
       console.error('Failed to rename:', err);
@@ -255,6 +268,14 @@ const Sidebar: Component<SidebarProps> = (props) => {
     const name = path.replace(/\\/g, '/').split('/').pop() || 'this item';
     setDeleteConfirm({ path, name });
     closeContextMenu();
+    // Folders: show how much would go before the user confirms
+    invoke<string[]>('delete_file', { path, dryRun: true })
+      .then(paths => {
+        if (deleteConfirm()?.path === path) {
+          setDeleteConfirm({ path, name, count: paths.length });
+        }
+      })
+      .catch(() => {});
   };
 
   const confirmDelete = async () => {
@@ -297,6 +318,52 @@ const Sidebar: Component<SidebarProps> = (props) => {
     closeContextMenu();
   };
 
+  // Encrypted one-time link, valid for a week; the link goes to the clipboard
+  const handleShareNote = async (path: string) => {
+    closeContextMenu();
+    if (!props.vaultPath) return;
+    try {
+      const share = await invoke<{ url: string }>('share_note', {
+        path,
+        vaultPath: props.vaultPath,
+        expiry: '1week',
+        options: { format: 'markdown', burn_after_reading: true, inline_images: true },
+      });
+      await navigator.clipboard.writeText(share.url);
+    } catch (err) {
+      console.error('Failed to share note:', err);
+    }
+  };
+
+  const handleOpenInEditor = async (path: string) => {
+    closeContextMenu();
+    if (!props.vaultPath) return;
+    try {
+      // Saves come back as file-modified-external events, picked up by App
+      await invoke('open_in_editor', { path, vaultPath: props.vaultPath });
+    } catch (err) {
+      console.error('Failed to open in external editor:', err);
+    }
+  };
+
+  const handleCopyAsRichText = async (path: string) => {
+    closeContextMenu();
+    if (!props.vaultPath) return;
+    try {
+      const content = await invoke<string>('read_file', { path });
+      const result = await invoke<{ skipped: { src: string; reason: string }[] }>('copy_as_rich_text', {
+        contentMarkdown: content,
+        vaultPath: props.vaultPath,
+        baseNotePath: path,
+      });
+      if (result.skipped.length > 0) {
+        console.warn('Images left out of rich text copy:', result.skipped);
+      }
+    } catch (err) {
+      console.error('Failed to copy as rich text:', err);
+    }
+  };
+
   const handleOpenInDefaultApp = async (path: string) => {
     try {
       await invoke('open_in_default_app', { path });
@@ -494,13 +561,19 @@ const Sidebar: Component<SidebarProps> = (props) => {
           onClick={() => {
             if (itemProps.entry.isDirectory) {
               toggleFolder(itemProps.entry.path);
-            } else if (!isBeingRenamed()) {
+            } else if (isBeingRenamed()) {
+              return;
+            } else if (itemProps.entry.kind === 'canvas') {
+              // There is no canvas view; hand canvases to the app that made them
+              invoke('open_in_default_app', { path: itemProps.entry.path })
+                .catch(err => console.error('Failed to open canvas:', err));
+            } else {
               props.onFileSelect(itemProps.entry.path);
             }
           }}
           onContextMenu={(e) => handleContextMenu(e, itemProps.entry.path, itemProps.entry.isDirectory)}
         >
-          <span>{itemProps.entry.isDirectory ? (isExpanded() ? '▼' : '▶') : '📄'}</span>
+          <span>{itemProps.entry.isDirectory ? (isExpanded() ? '▼' : '▶') : itemProps.entry.kind === 'canvas' ? '🗂' : '📄'}</span>
           <Show
             when={!isBeingRenamed()}
             fallback={
@@ -510,16 +583,22 @@ const Sidebar: Component<SidebarProps> = (props) => {
                 value={renameValue()}
                 onInput={(e) => setRenameValue(e.currentTarget.value)}
                 onKeyDown={(e) => {
-                  if (e.key === 'Enter') confirmRename(itemProps.entry.path);
+                  if (e.key === 'Enter') confirmRename(itemProps.entry.path, itemProps.entry.isDirectory);
                   if (e.key === 'Escape') setIsRenaming(null);
                 }}
-                onBlur={() => confirmRename(itemProps.entry.path)}
+                onBlur={() => confirmRename(itemProps.entry.path, itemProps.entry.isDirectory)}
                 autofocus
                 onClick={(e) => e.stopPropagation()}
               />
             }
           >
             <span>{itemProps.entry.name.replace(/\.md$/i, '')}</span>
+            <Show when={itemProps.entry.isDirectory && itemProps.entry.noteCount != null}>
+              <span class="folder-note-count">({itemProps.entry.noteCount})</span>
+            </Show>
+            <Show when={itemProps.entry.isDirectory && isToday(itemProps.entry.latestModified)}>
+              <span class="folder-modified-dot" title="Contains notes modified today" />
+            </Show>
           </Show>
         </div>
         <Show when={itemProps.entry.isDirectory && isExpanded()}>
@@ -1012,6 +1091,17 @@ const Sidebar: Component<SidebarProps> = (props) => {
           <div class="context-menu-item" onClick={() => handleCopyPath(contextMenu()!.path)}>
             Copy path
           </div>
+          <Show when={contextMenu()!.path.endsWith('.md')}>
+            <div class="context-menu-item" onClick={() => handleCopyAsRichText(contextMenu()!.path)}>
+              Copy as rich text
+            </div>
+            <div class="context-menu-item" onClick={() => handleShareNote(contextMenu()!.path)}>
+              Share via encrypted link
+            </div>
+            <div class="context-menu-item" onClick={() => handleOpenInEditor(contextMenu()!.path)}>
+              Open in external editor
+            </div>
+          </Show>
           <Show when={!contextMenu()!.isDir}>
             <div class="context-menu-item" onClick={() => handleOpenInDefaultApp(contextMenu()!.path)}>
               Open in default app
@@ -1046,7 +1136,11 @@ const Sidebar: Component<SidebarProps> = (props) => {
               </button>
             </div>
             <div class="modal-body">
-              <p>This will permanently delete this item. This action cannot be undone.</p>
+              <p>
+                {(deleteConfirm()!.count ?? 1) > 1
+                  ? `This will permanently delete this folder and the ${deleteConfirm()!.count! - 1} items inside it. This action cannot be undone.`
+                  : 'This will permanently delete this item. This action cannot be undone.'}
+              </p>
             </div>
             <div class="modal-footer">
               <button class="setting-button secondary" onClick={() => setDeleteConfirm(null)}>Cancel</button>
@@ -1,5 +1,6 @@
 import { Component, createSignal, onMount, onCleanup } from 'solid-js';
 import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from '../lib/errors';
 
 interface TerminalProps {
   vaultPath: string | null;
@@ -36,7 +37,7 @@ const Terminal: Component<TerminalProps> = (props) => {
       });
       addOutput('opencode launched successfully');
     } catch (err) {
-      addOutput(`Error: ${err}`);
+      addOutput(`Error: ${errorMessage(err)}`);
     } finally {
       setIsRunning(false);
     }
@@ -57,7 +58,7 @@ const Terminal: Component<TerminalProps> = (props) => {
         result.split('\n').forEach(line => addOutput(line));
       }
     } catch (err) {
-      addOutput(`Error: ${err}`);
+      addOutput(`Error: ${errorMessage(err)}`);
     } finally {
       setIsRunning(false);
     }
@@ -0,0 +1,24 @@
+/**
+ * Why an asset failed to load. Media elements only report that loading failed;
+ * the asset protocol answers errors with a `{ error, code }` JSON body.
+ */
+
+export interface AssetError {
+  error: string;
+  code: 'path_traversal' | 'not_found' | 'outside_vault' | 'not_a_file' | 'unreadable' | 'expired_token';
+}
+
+/**
+ * Request an asset URL again to read the reason it failed, or null if there is none
+ */
+export async function fetchAssetError(src: string): Promise<AssetError | null> {
+  try {
+    const response = await fetch(src);
+    if (response.ok || !response.headers.get('Content-Type')?.includes('application/json')) {
+      return null;
+    }
+    return (await response.json()) as AssetError;
+  } catch {
+    return null;
+  }
+}
@@ -0,0 +1,47 @@
+/**
+ * Binary file reads and writes that stay off the JSON IPC path when large
+ *
+ * Small files go through read_binary_file / write_binary_file as before. Above
+ * the threshold, reads are fetched once through the asset protocol and writes
+ * are streamed as raw chunks, so a big attachment is never turned into a huge
+ * JSON array in one message.
+ */
+
+import { convertFileSrc, invoke } from '@tauri-apps/api/core';
+
+/** Files up to this size use the plain commands */
+const INLINE_LIMIT = 4 * 1024 * 1024;
+/** Bytes sent per append when streaming a write */
+const CHUNK_SIZE = 1024 * 1024;
+
+export async function readBinaryFile(path: string, vaultPath?: string): Promise<Uint8Array> {
+  const stats = await invoke<{ size: number }>('get_file_stats', { path });
+  if (stats.size <= INLINE_LIMIT) {
+    return new Uint8Array(await invoke<number[]>('read_binary_file', { path, vaultPath }));
+  }
+  const token = await invoke<string>('read_binary_file_token', { path, vaultPath });
+  const response = await fetch(convertFileSrc(`__transfer/${token}`));
+  if (!response.ok) {
+    throw new Error(`Failed to read ${path}: ${response.status}`);
+  }
+  return new Uint8Array(await response.arrayBuffer());
+}
+
+export async function writeBinaryFile(path: string, data: Uint8Array, vaultPath?: string): Promise<void> {
+  if (data.length <= INLINE_LIMIT) {
+    await invoke('write_binary_file', { path, data: Array.from(data), vaultPath });
+    return;
+  }
+  const token = await invoke<string>('write_binary_file_begin', { path, vaultPath });
+  try {
+    for (let offset = 0; offset < data.length; offset += CHUNK_SIZE) {
+      await invoke('write_binary_file_append', data.subarray(offset, offset + CHUNK_SIZE), {
+        headers: { 'x-transfer-token': token },
+      });
+    }
+    await invoke('write_binary_file_commit', { token });
+  } catch (err) {
+    await invoke('write_binary_file_abort', { token }).catch(() => {});
+    throw err;
+  }
+}
@@ -46,6 +46,38 @@ export function getDailyNotePathForDate(vaultPath: string, config: DailyNotesCon
   return `${vaultPath}/${config.folder}/${filename}.md`;
 }
 
+interface CalendarEvent {
+  summary: string;
+  location: string | null;
+  start: string;
+  end: string;
+  all_day: boolean;
+}
+
+/**
+ * Today's events from the configured calendar feeds as a markdown list, for {{events}}
+ */
+async function formatEvents(date: Date): Promise<string> {
+  const day = dayjs(date).format('YYYY-MM-DD');
+  try {
+    const result = await invoke<{ events: CalendarEvent[]; warnings: string[] }>('get_events', { start: day, end: day });
+    if (result.warnings.length > 0) {
+      console.warn('Calendar feeds not refreshed:', result.warnings);
+    }
+    if (result.events.length === 0) return '- No events';
+    return result.events
+      .map((event) => {
+        const time = event.all_day ? 'All day' : `${dayjs(event.start).format('HH:mm')}–${dayjs(event.end).format('HH:mm')}`;
+        const location = event.location ? ` (${event.location})` : '';
+        return `- ${time} ${event.summary}${location}`;
+      })
+      .join('\n');
+  } catch (err) {
+    console.error('Failed to load calendar events:', err);
+    return '';
+  }
+}
+
 /**
  * Interpolate template variables
  */
@@ -96,9 +128,12 @@ export async function openDailyNote(
   }
   
   // Create the note with the template content
-  const content = interpolateTemplate(config.template);
-  await invoke('create_file', { path: notePath });
-  await invoke('write_file', { path: notePath, content });
+  let content = interpolateTemplate(config.template);
+  if (content.includes('{{events}}')) {
+    const events = await formatEvents(new Date());
+    content = content.replace(/\{\{events\}\}/g, () => events);
+  }
+  await invoke('create_file', { path: notePath, content });
   
   return { path: notePath, isNew: true };
 }
@@ -5,11 +5,43 @@
  * for fast resolution of ![[embed]] targets.
  */
 
-// Supported file extensions by type
+import { invoke } from '@tauri-apps/api/core';
+
+// Supported file extensions by type. These are fallbacks until
+// syncSupportedExtensions() replaces them with the backend's lists.
 export const IMAGE_EXTENSIONS = ['avif', 'bmp', 'gif', 'jpeg', 'jpg', 'png', 'svg', 'webp'];
 export const AUDIO_EXTENSIONS = ['flac', 'm4a', 'mp3', 'ogg', 'wav', 'webm', '3gp'];
 export const VIDEO_EXTENSIONS = ['mkv', 'mov', 'mp4', 'ogv', 'webm'];
 export const PDF_EXTENSIONS = ['pdf'];
+export const ALL_EXTENSIONS = [...IMAGE_EXTENSIONS, ...VIDEO_EXTENSIONS, ...AUDIO_EXTENSIONS, ...PDF_EXTENSIONS];
+
+interface SupportedAssetExtensions {
+  image: string[];
+  audio: string[];
+  video: string[];
+  pdf: string[];
+}
+
+/**
+ * Load the embeddable extensions from the backend so both sides agree.
+ * The exported arrays are updated in place, keeping existing imports valid.
+ */
+export async function syncSupportedExtensions(): Promise<void> {
+  try {
+    const supported = await invoke<SupportedAssetExtensions>('get_supported_asset_extensions');
+    IMAGE_EXTENSIONS.splice(0, IMAGE_EXTENSIONS.length, ...supported.image);
+    AUDIO_EXTENSIONS.splice(0, AUDIO_EXTENSIONS.length, ...supported.audio);
+    VIDEO_EXTENSIONS.splice(0, VIDEO_EXTENSIONS.length, ...supported.video);
+    PDF_EXTENSIONS.splice(0, PDF_EXTENSIONS.length, ...supported.pdf);
+    ALL_EXTENSIONS.splice(
+      0,
+      ALL_EXTENSIONS.length,
+      ...new Set([...supported.image, ...supported.video, ...supported.audio, ...supported.pdf])
+    );
+  } catch (err) {
+    console.error('Failed to load supported asset extensions:', err);
+  }
+}
 
 export type EmbedFileType = 'image' | 'audio' | 'video' | 'pdf' | 'note' | 'unknown';
 
@@ -6,7 +6,7 @@
  * Also tracks heading and block references in wikilinks.
  */
 
-import { NoteGraph } from './note-index';
+import { NoteGraph, isCanvasPath } from './note-index';
 
 export interface BacklinkInfo {
   sourcePath: string;      // Path to the file containing the mention
@@ -149,6 +149,19 @@ export function getBacklinksForNote(
 
     if (normalizedLinkTo === normalizedTargetPath && normalizedLinkFrom !== normalizedTargetPath) {
       const sourceNode = graph.nodes.find(n => normalizePath(n.id) === normalizedLinkFrom);
+      // A canvas card has no line of text around it
+      if (isCanvasPath(link.from)) {
+        linked.push({
+          sourcePath: link.from,
+          sourceName: sourceNode?.name || getBaseName(link.from),
+          context: 'Card on canvas',
+          lineNumber: 1,
+          isLinked: true,
+          mentionStart: 0,
+          mentionEnd: 0,
+        });
+        continue;
+      }
       const content = fileContents.get(link.from) || '';
       const { context, lineNumber, mentionStart, mentionEnd, heading, blockId } = findLinkContext(content, link.toRaw);
 
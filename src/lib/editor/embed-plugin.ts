@@ -19,6 +19,8 @@ import {
 import { NoteIndex, resolveWikilink } from './note-index';
 import { convertFileSrc } from '@tauri-apps/api/core';
 import { invoke } from '@tauri-apps/api/core';
+import { trackAudio } from '../now-playing';
+import { fetchAssetError } from '../asset-errors';
 
 // Plugin key
 export const embedPluginKey = new PluginKey('embed');
@@ -175,6 +177,7 @@ function renderAudio(container: HTMLElement, resolvedPath: string): void {
   audio.controls = true;
   audio.className = 'embed-audio';
   audio.preload = 'metadata';
+  trackAudio(audio, resolvedPath.split(/[/\\]/).pop() || resolvedPath);
   audio.onerror = () => {
     container.innerHTML = '';
     renderBroken(container, resolvedPath, 'audio');
@@ -324,6 +327,14 @@ function renderBroken(container: HTMLElement, target: string, fileType: EmbedFil
   broken.appendChild(icon);
   broken.appendChild(text);
   container.appendChild(broken);
+
+  // Resolved media paths went through the asset protocol, which can say why they failed
+  const isResolvedPath = target.startsWith('/') || /^[A-Za-z]:[\\/]/.test(target);
+  if (isResolvedPath && fileType !== 'note' && fileType !== 'unknown') {
+    fetchAssetError(getMediaSrc(target)).then((reason) => {
+      if (reason) text.textContent = `${target} (${reason.error})`;
+    });
+  }
 }
 
 function getFileTypeIcon(fileType: EmbedFileType): string {
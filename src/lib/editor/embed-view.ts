@@ -17,6 +17,8 @@ import {
 import { NoteIndex, resolveWikilink } from './note-index';
 import { convertFileSrc } from '@tauri-apps/api/core';
 import { invoke } from '@tauri-apps/api/core';
+import { trackAudio } from '../now-playing';
+import { fetchAssetError } from '../asset-errors';
 
 // Module-level state (same pattern as wikilink-plugin.ts)
 let currentAssetIndex: AssetIndex | null = null;
@@ -85,6 +87,7 @@ function renderAudio(container: HTMLElement, resolvedPath: string): void {
   audio.controls = true;
   audio.className = 'embed-audio';
   audio.preload = 'metadata';
+  trackAudio(audio, resolvedPath.split(/[/\\]/).pop() || resolvedPath);
 
   audio.onerror = () => {
     container.innerHTML = '';
@@ -337,6 +340,14 @@ function renderBroken(
   broken.appendChild(icon);
   broken.appendChild(text);
   container.appendChild(broken);
+
+  // Resolved media paths went through the asset protocol, which can say why they failed
+  const isResolvedPath = target.startsWith('/') || /^[A-Za-z]:[\\/]/.test(target);
+  if (isResolvedPath && fileType !== 'note' && fileType !== 'unknown') {
+    fetchAssetError(getMediaSrc(target)).then((reason) => {
+      if (reason) text.textContent = `${target} (${reason.error})`;
+    });
+  }
 }
 
 /**
@@ -9,6 +9,7 @@ export interface FileEntry {
   name: string;
   path: string;
   isDirectory: boolean;
+  kind?: 'folder' | 'note' | 'canvas';
   children?: FileEntry[];
 }
 
@@ -280,6 +281,17 @@ export interface NoteGraph {
   links: NoteLink[];
 }
 
+// A file card on a canvas, as returned by the list_canvas_links command
+export interface CanvasLink {
+  source: string;    // canvas file path
+  target: string;    // referenced file path
+  node_id: string;
+}
+
+export function isCanvasPath(path: string): boolean {
+  return path.toLowerCase().endsWith('.canvas');
+}
+
 // Regex to extract [[wikilinks]] from content - matches [[target]] or [[target|alias]]
 // Also handles escaped brackets \[\[ which Milkdown may produce
 const WIKILINK_REGEX = /\\?\[\\?\[([^\]|]+)(?:\|[^\]]+)?\\?\]\\?\]/g;
@@ -310,11 +322,13 @@ export function extractLinksFromContent(content: string): string[] {
  * @param vaultPath - Root path of the vault
  * @param noteIndex - The built note index
  * @param readFile - Function to read file content (passed from caller to avoid Tauri dependency)
+ * @param canvasLinks - File cards on canvases, added as links from the canvas to the file
  */
 export async function buildNoteGraph(
   vaultPath: string,
   noteIndex: NoteIndex,
-  readFile: (path: string) => Promise<string>
+  readFile: (path: string) => Promise<string>,
+  canvasLinks: CanvasLink[] = []
 ): Promise<NoteGraph> {
   const nodes: Map<string, NoteNode> = new Map();
   const links: NoteLink[] = [];
@@ -367,6 +381,32 @@ export async function buildNoteGraph(
     }
   }
 
+  // Canvases are link sources only; their cards point at files by full path
+  for (const card of canvasLinks) {
+    let canvasNode = nodes.get(card.source);
+    if (!canvasNode) {
+      canvasNode = {
+        id: card.source,
+        name: getBaseName(card.source).replace(/\.canvas$/i, ''),
+        incomingCount: 0,
+        outgoingCount: 0,
+      };
+      nodes.set(card.source, canvasNode);
+    }
+    canvasNode.outgoingCount++;
+
+    const exists = noteIndex.allPaths.has(card.target);
+    links.push({
+      from: card.source,
+      to: card.target,
+      toRaw: getBaseName(card.target),
+      exists,
+    });
+    if (exists) {
+      incomingCounts.set(card.target, (incomingCounts.get(card.target) || 0) + 1);
+    }
+  }
+
   // Update incoming counts on nodes
   for (const [path, count] of incomingCounts) {
     const node = nodes.get(path);
@@ -2,6 +2,8 @@ import { $prose } from '@milkdown/utils';
 import { Plugin, PluginKey } from '@milkdown/prose/state';
 import type { EditorView } from '@milkdown/prose/view';
 import { writeFile, readFile, mkdir, exists } from '@tauri-apps/plugin-fs';
+import { ALL_EXTENSIONS } from './asset-index';
+import { attachmentFolder } from '../vault-config';
 
 // Module-level state
 let currentVaultPath: string | null = null;
@@ -15,14 +17,6 @@ export const setOnFilesUploaded = (callback: (() => void) | null) => {
   onFilesUploaded = callback;
 };
 
-// Supported file extensions
-const IMAGE_EXTENSIONS = ['png', 'jpg', 'jpeg', 'gif', 'webp', 'svg', 'bmp', 'avif'];
-const VIDEO_EXTENSIONS = ['mp4', 'webm', 'mov', 'mkv', 'ogv'];
-const AUDIO_EXTENSIONS = ['mp3', 'wav', 'ogg', 'flac', 'm4a', '3gp'];
-const PDF_EXTENSIONS = ['pdf'];
-
-const ALL_EXTENSIONS = [...IMAGE_EXTENSIONS, ...VIDEO_EXTENSIONS, ...AUDIO_EXTENSIONS, ...PDF_EXTENSIONS];
-
 /**
  * Join path segments with forward slashes (works on all platforms with Tauri plugin-fs)
  */
@@ -87,7 +81,8 @@ async function saveFileToVault(file: File, vaultPath: string): Promise<string> {
 
   fileName = sanitizeFileName(fileName);
 
-  const attachmentsDir = joinPath(vaultPath, 'attachments');
+  const folder = await attachmentFolder(vaultPath);
+  const attachmentsDir = joinPath(vaultPath, folder);
 
   // Ensure attachments directory exists
   if (!(await exists(attachmentsDir))) {
@@ -97,7 +92,7 @@ async function saveFileToVault(file: File, vaultPath: string): Promise<string> {
 
   fileName = await getUniqueFileName(attachmentsDir, fileName);
 
-  const relativePath = `attachments/${fileName}`;
+  const relativePath = `${folder}/${fileName}`;
   const fullPath = joinPath(vaultPath, relativePath);
 
   console.log('[Upload] Saving to fullPath:', fullPath);
@@ -137,7 +132,8 @@ async function copyFileToVault(sourcePath: string, vaultPath: string): Promise<s
 
   fileName = sanitizeFileName(fileName);
 
-  const attachmentsDir = joinPath(vaultPath, 'attachments');
+  const folder = await attachmentFolder(vaultPath);
+  const attachmentsDir = joinPath(vaultPath, folder);
 
   // Ensure attachments directory exists
   if (!(await exists(attachmentsDir))) {
@@ -147,7 +143,7 @@ async function copyFileToVault(sourcePath: string, vaultPath: string): Promise<s
 
   fileName = await getUniqueFileName(attachmentsDir, fileName);
 
-  const relativePath = `attachments/${fileName}`;
+  const relativePath = `${folder}/${fileName}`;
   const fullPath = joinPath(vaultPath, relativePath);
 
   console.log('[Upload] Copying to fullPath:', fullPath);
@@ -0,0 +1,50 @@
+/**
+ * Errors returned by backend commands
+ *
+ * Commands reject with `{ code, message, context }`. Branch on `code`, never on
+ * the message text.
+ */
+
+export type ErrorCode =
+  | 'NOT_FOUND'
+  | 'ALREADY_EXISTS'
+  | 'PERMISSION_DENIED'
+  | 'OUTSIDE_VAULT'
+  | 'VAULT_UNAVAILABLE'
+  | 'VAULT_LOCKED'
+  | 'INVALID_INPUT'
+  | 'SESSION_NOT_FOUND'
+  | 'LIMIT_REACHED'
+  | 'UNSUPPORTED'
+  | 'NETWORK'
+  | 'WATCHER'
+  | 'ARCHIVE'
+  | 'KEYRING'
+  | 'IO'
+  | 'INTERNAL';
+
+export interface OnyxError {
+  code: ErrorCode;
+  message: string;
+  context?: Record<string, string>;
+}
+
+export function isOnyxError(err: unknown): err is OnyxError {
+  return typeof err === 'object' && err !== null && 'code' in err && 'message' in err;
+}
+
+/**
+ * The error's code, or null for errors that didn't come from a command
+ */
+export function errorCode(err: unknown): ErrorCode | null {
+  return isOnyxError(err) ? err.code : null;
+}
+
+/**
+ * Human-readable text for any command error
+ */
+export function errorMessage(err: unknown): string {
+  if (isOnyxError(err)) return err.message;
+  if (err instanceof Error) return err.message;
+  return String(err);
+}
@@ -0,0 +1,35 @@
+/**
+ * Tail a file inside the vault (e.g. a build log shown next to a note)
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
+interface FileTail {
+  lines: string[];
+  event: string | null;
+}
+
+/**
+ * Read the last `lines` lines of `path`. With `onAppend`, lines appended later are
+ * passed to it until the returned `stop` is called.
+ */
+export async function tailFile(
+  path: string,
+  vaultPath: string,
+  lines: number,
+  onAppend?: (lines: string[]) => void,
+): Promise<{ lines: string[]; stop: () => Promise<void> }> {
+  const tail = await invoke<FileTail>('tail_file', { path, lines, follow: !!onAppend, vaultPath });
+  if (!onAppend || !tail.event) {
+    return { lines: tail.lines, stop: async () => {} };
+  }
+  const unlisten = await listen<string[]>(tail.event, (event) => onAppend(event.payload));
+  return {
+    lines: tail.lines,
+    stop: async () => {
+      unlisten();
+      await invoke('untail_file', { path });
+    },
+  };
+}
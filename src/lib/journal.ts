@@ -0,0 +1,94 @@
+/**
+ * Crash-recovery journal
+ *
+ * Streams editor changes to the backend between autosaves so typing done
+ * since the last save survives a crash. Each change is sent as the smallest
+ * single replacement that turns the previous content into the new one.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { ask } from '@tauri-apps/plugin-dialog';
+
+type JournalRecord =
+  | { type: 'snapshot'; content: string }
+  | { type: 'delta'; from: number; to: number; insert: string };
+
+export interface UnrecoveredJournal {
+  path: string;
+  revision: number;
+  modified: number;
+  content: string;
+}
+
+const revisions = new Map<string, number>();
+
+/**
+ * Replacement turning `previous` into `next`, in UTF-16 offsets
+ */
+function diff(previous: string, next: string): JournalRecord {
+  let start = 0;
+  const max = Math.min(previous.length, next.length);
+  while (start < max && previous[start] === next[start]) start++;
+
+  let end = 0;
+  while (
+    end < max - start &&
+    previous[previous.length - 1 - end] === next[next.length - 1 - end]
+  ) {
+    end++;
+  }
+
+  return {
+    type: 'delta',
+    from: start,
+    to: previous.length - end,
+    insert: next.slice(start, next.length - end),
+  };
+}
+
+/**
+ * Journal an edit to `path`. Failures are logged, never surfaced: the journal
+ * is a safety net and must not get in the way of editing.
+ */
+export function journalChange(path: string, previous: string, next: string): void {
+  if (previous === next) return;
+  const revision = (revisions.get(path) ?? 0) + 1;
+  revisions.set(path, revision);
+  invoke('journal_append', { path, revision, record: diff(previous, next) }).catch((err) => {
+    console.error('Failed to journal change:', err);
+  });
+}
+
+/**
+ * Offer to restore unsaved edits left behind by a crash
+ */
+export async function offerJournalRecovery(vaultPath: string): Promise<string[]> {
+  const restored: string[] = [];
+  let journals: UnrecoveredJournal[] = [];
+  try {
+    journals = await invoke<UnrecoveredJournal[]>('get_unrecovered_journals', { vaultPath });
+  } catch (err) {
+    console.error('Failed to check for unsaved changes:', err);
+    return restored;
+  }
+
+  for (const journal of journals) {
+    const name = journal.path.split(/[/\\]/).pop() ?? journal.path;
+    const when = new Date(journal.modified).toLocaleString();
+    const restore = await ask(
+      `"${name}" has unsaved changes from ${when} that were not written before Onyx closed. Restore them?`,
+      { title: 'Recover unsaved changes', kind: 'warning', okLabel: 'Restore', cancelLabel: 'Discard' }
+    );
+    try {
+      if (restore) {
+        await invoke('accept_recovery', { path: journal.path, vaultPath });
+        restored.push(journal.path);
+      } else {
+        await invoke('discard_recovery', { path: journal.path, vaultPath });
+      }
+    } catch (err) {
+      console.error('Failed to finish recovery for', journal.path, err);
+    }
+  }
+  return restored;
+}
@@ -0,0 +1,98 @@
+/**
+ * System media controls for embedded audio
+ *
+ * Reports the playing audio embed to the backend, which publishes it to the
+ * system media panel (MPRIS on Linux, SMTC on Windows), and applies media key
+ * presses the backend sends back as `media-key` events.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
+type MediaKey =
+  | { action: 'play-pause' | 'play' | 'pause' | 'stop' }
+  | { action: 'seek'; offset_secs: number }
+  | { action: 'set-position'; position_secs: number };
+
+/** Position updates are sent at most this often while playing */
+const POSITION_INTERVAL_MS = 5000;
+
+let current: HTMLAudioElement | null = null;
+let listening = false;
+
+function report(audio: HTMLAudioElement, title: string, notePath: string | null) {
+  invoke('set_now_playing', {
+    title,
+    notePath,
+    position: audio.currentTime,
+    playing: !audio.paused,
+    duration: Number.isFinite(audio.duration) ? audio.duration : null,
+  }).catch((err) => console.error('Failed to update media controls:', err));
+}
+
+function clear(audio: HTMLAudioElement) {
+  if (current !== audio) return;
+  current = null;
+  invoke('clear_now_playing').catch(() => {});
+}
+
+function listenForKeys() {
+  if (listening) return;
+  listening = true;
+  listen<MediaKey>('media-key', (event) => {
+    const audio = current;
+    if (!audio) return;
+    const key = event.payload;
+    switch (key.action) {
+      case 'play-pause':
+        if (audio.paused) audio.play().catch(() => {});
+        else audio.pause();
+        break;
+      case 'play':
+        audio.play().catch(() => {});
+        break;
+      case 'pause':
+        audio.pause();
+        break;
+      case 'stop':
+        audio.pause();
+        audio.currentTime = 0;
+        clear(audio);
+        break;
+      case 'seek':
+        audio.currentTime = Math.max(0, audio.currentTime + key.offset_secs);
+        break;
+      case 'set-position':
+        audio.currentTime = key.position_secs;
+        break;
+    }
+  });
+}
+
+/**
+ * Expose an embedded audio element to the system media controls while it plays
+ */
+export function trackAudio(audio: HTMLAudioElement, title: string, notePath: string | null = null) {
+  let lastReport = 0;
+  const update = () => {
+    current = audio;
+    lastReport = Date.now();
+    report(audio, title, notePath);
+  };
+
+  audio.addEventListener('play', () => {
+    listenForKeys();
+    update();
+  });
+  audio.addEventListener('pause', () => {
+    if (current === audio) update();
+  });
+  audio.addEventListener('seeked', () => {
+    if (current === audio) update();
+  });
+  audio.addEventListener('timeupdate', () => {
+    if (current === audio && !audio.paused && Date.now() - lastReport > POSITION_INTERVAL_MS) update();
+  });
+  audio.addEventListener('ended', () => clear(audio));
+  audio.addEventListener('emptied', () => clear(audio));
+}
@@ -153,27 +153,31 @@ export function getSkillGitHubUrl(topSource: string, skillId: string): string {
 
 /**
  * Download and install a skill from skills.sh
- * Uses Tauri backend to bypass CORS restrictions
+ * The backend looks the skill up in the catalog, downloads it and installs it in one step,
+ * emitting `skill-install-progress` as it goes
  */
-export async function installSkillFromSkillsSh(skill: SkillsShSkill): Promise<void> {
-  const skillUrl = getSkillFileUrl(skill.topSource, skill.id, 'SKILL.md');
-  
+export async function installSkillFromSkillsSh(skill: SkillsShSkill): Promise<string> {
   try {
-    // Download SKILL.md using Tauri backend to bypass CORS
-    const content = await invoke<string>('fetch_skill_file', { url: skillUrl });
-    
-    // Save the skill file using Tauri backend
-    await invoke('skill_save_file', {
-      skillId: skill.id,
-      fileName: 'SKILL.md',
-      content,
-    });
+    return await invoke<string>('install_skill_from_skills_sh', { skillId: skill.id, onConflict: 'overwrite' });
   } catch (err) {
     console.error(`Failed to install skill ${skill.id}:`, err);
     throw err;
   }
 }
 
+/**
+ * Fetch the SKILL.md of skills likely to be opened next, so installing or viewing
+ * them doesn't wait on the network. The backend emits `skill-prefetched` per file.
+ */
+export async function prefetchSkillsShFiles(skills: SkillsShSkill[]): Promise<void> {
+  const urls = skills.map(skill => getSkillFileUrl(skill.topSource, skill.id, 'SKILL.md'));
+  try {
+    await invoke<number>('prefetch_skill_files', { urls });
+  } catch (err) {
+    console.error('Failed to prefetch skill files:', err);
+  }
+}
+
 /**
  * Check if a skill is installed
  */
@@ -192,11 +196,38 @@ export async function deleteSkill(skillId: string): Promise<void> {
   await invoke('skill_delete', { skillId });
 }
 
+export interface InstalledSkill {
+  id: string;
+  staged: boolean;
+}
+
 /**
  * Get list of all installed skill IDs
  */
 export async function getInstalledSkillIds(): Promise<string[]> {
-  return await invoke<string[]>('skill_list_installed');
+  const installed = await invoke<InstalledSkill[]>('skill_list_installed');
+  return installed.map((skill) => skill.id);
+}
+
+/**
+ * Get installed skills, optionally including ones still in staging
+ */
+export async function getInstalledSkills(includeStaged = false): Promise<InstalledSkill[]> {
+  return await invoke<InstalledSkill[]>('skill_list_installed', { includeStaged });
+}
+
+/**
+ * Import a skill ZIP into the staging area for testing
+ */
+export async function stageSkillZip(zipPath: string): Promise<string> {
+  return await invoke<string>('skill_stage_import', { zipPath });
+}
+
+/**
+ * Move a staged skill into the active skills directory
+ */
+export async function promoteSkill(skillId: string): Promise<void> {
+  await invoke('skill_promote', { skillId });
 }
 
 /**
@@ -121,8 +121,7 @@ export async function createNoteFromTemplate(
   
   // Create the note
   const notePath = `${targetFolderPath}/${noteName}.md`;
-  await invoke('create_file', { path: notePath });
-  await invoke('write_file', { path: notePath, content });
+  await invoke('create_file', { path: notePath, content });
   
   return notePath;
 }
@@ -168,8 +167,7 @@ Created: {{date}}
 `;
     
     try {
-      await invoke('create_file', { path: samplePath });
-      await invoke('write_file', { path: samplePath, content: sampleContent });
+      await invoke('create_file', { path: samplePath, content: sampleContent });
     } catch {
       // Template may already exist
     }
@@ -0,0 +1,50 @@
+/**
+ * Per-vault configuration
+ *
+ * `.onyx/config.json` inside a vault overrides the global (localStorage) options
+ * for that vault. Unset fields fall back to the global value.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import type { DailyNotesConfig } from './daily-notes';
+import type { TemplatesConfig } from './templates';
+
+export interface VaultConfig {
+  daily_notes_folder: string | null;
+  templates_folder: string | null;
+  attachment_folder: string | null;
+}
+
+export const DEFAULT_ATTACHMENT_FOLDER = 'attachments';
+
+/**
+ * Load the vault's config, or an empty one if it can't be read
+ */
+export async function loadVaultConfig(vaultPath: string): Promise<VaultConfig> {
+  try {
+    return await invoke<VaultConfig>('load_vault_config', { vaultPath });
+  } catch (e) {
+    console.error('Failed to load vault config:', e);
+    return { daily_notes_folder: null, templates_folder: null, attachment_folder: null };
+  }
+}
+
+export async function saveVaultConfig(vaultPath: string, config: VaultConfig): Promise<void> {
+  await invoke('save_vault_config', { vaultPath, config });
+}
+
+export function mergeDailyNotesConfig(global: DailyNotesConfig, vault: VaultConfig): DailyNotesConfig {
+  return vault.daily_notes_folder ? { ...global, folder: vault.daily_notes_folder } : global;
+}
+
+export function mergeTemplatesConfig(global: TemplatesConfig, vault: VaultConfig): TemplatesConfig {
+  return vault.templates_folder ? { ...global, folder: vault.templates_folder } : global;
+}
+
+/**
+ * Vault-relative folder new attachments are saved to
+ */
+export async function attachmentFolder(vaultPath: string): Promise<string> {
+  const config = await loadVaultConfig(vaultPath);
+  return config.attachment_folder || DEFAULT_ATTACHMENT_FOLDER;
+}
@@ -0,0 +1,52 @@
+/**
+ * Import a folder of loose markdown files as a new vault
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
+export interface ImportOptions {
+  /** Drop folders whose only entry is another folder */
+  flatten_folders?: boolean;
+  /** Vault-relative folder for images found next to notes */
+  attachment_folder?: string | null;
+}
+
+export interface ImportProgress {
+  files_done: number;
+  files_total: number;
+  current: string;
+  complete: boolean;
+}
+
+export interface ImportResult {
+  files_copied: number;
+  files_changed: number;
+  links_rewritten: number;
+  skipped: string[];
+  report: string;
+}
+
+/**
+ * Copy `source` into the new vault `dest`, reporting progress per file.
+ * The source folder is never modified.
+ */
+export async function importFolderAsVault(
+  source: string,
+  dest: string,
+  options: ImportOptions = {},
+  onProgress?: (progress: ImportProgress) => void,
+): Promise<ImportResult> {
+  const unlisten = onProgress
+    ? await listen<ImportProgress>('vault-import-progress', (event) => onProgress(event.payload))
+    : null;
+  try {
+    return await invoke<ImportResult>('import_folder_as_vault', { source, dest, options });
+  } finally {
+    unlisten?.();
+  }
+}
+
+export async function cancelVaultImport(): Promise<void> {
+  await invoke('cancel_vault_import');
+}